@@ -0,0 +1,16 @@
+use raw_window_handle::HasRawWindowHandle;
+use winit::{event_loop::EventLoop, window::WindowBuilder};
+use wv::*;
+
+fn main() {
+    let event_loop = EventLoop::new();
+    let window = WindowBuilder::new()
+        .with_title("Host Window")
+        .with_inner_size(winit::dpi::LogicalSize::new(800, 300))
+        .build(&event_loop)
+        .unwrap();
+
+    let mut wv = Webview::create_from_window_handle(false, window.raw_window_handle()).unwrap();
+    wv.navigate("https://www.wikipedia.com").unwrap();
+    wv.run().unwrap();
+}
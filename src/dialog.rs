@@ -0,0 +1,37 @@
+use crate::Webview;
+use std::path::PathBuf;
+use tinyfiledialogs as tfd;
+
+pub use tinyfiledialogs::MessageBoxIcon;
+
+impl Webview {
+    /// Open a native "Open File" dialog
+    pub fn open_file(
+        &self,
+        title: &str,
+        default_path: &str,
+        filter: Option<(&[&str], &str)>,
+    ) -> Option<PathBuf> {
+        tfd::open_file_dialog(title, default_path, filter).map(PathBuf::from)
+    }
+
+    /// Open a native "Save As" dialog
+    pub fn save_file(&self, title: &str, default_path: &str) -> Option<PathBuf> {
+        tfd::save_file_dialog(title, default_path).map(PathBuf::from)
+    }
+
+    /// Open a native folder picker
+    pub fn select_folder(&self, title: &str, default_path: &str) -> Option<PathBuf> {
+        tfd::select_folder_dialog(title, default_path).map(PathBuf::from)
+    }
+
+    /// Show a native Ok/Cancel message box
+    pub fn message_box(&self, title: &str, message: &str, icon: MessageBoxIcon) -> bool {
+        tfd::message_box_ok_cancel(title, message, icon, tfd::OkCancel::Cancel) == tfd::OkCancel::Ok
+    }
+
+    /// Show a native text input prompt
+    pub fn input_box(&self, title: &str, message: &str, default: &str) -> Option<String> {
+        tfd::input_box(title, message, default)
+    }
+}
@@ -2,6 +2,8 @@
 
 // Uses code from https://github.com/webview/webview_rust/blob/dev/src/webview.rs
 
+pub mod dialog;
+
 use wv_sys::*;
 
 use std::{
@@ -125,6 +127,58 @@ pub enum SizeHint {
     Fixed = 3,
 }
 
+/// Content to load into a `Webview`, passed to [`Webview::load`]
+#[derive(Debug, Clone, Copy)]
+pub enum Content<'a> {
+    /// A URL, including `data:`, `file:` and `http(s):` schemes
+    Url(&'a str),
+    /// Inline HTML markup, percent-encoded internally into a `data:` URL
+    Html(&'a str),
+}
+
+/// Builds a percent-encoded `data:` URL for inline HTML markup
+fn html_data_url(html: &str) -> String {
+    format!("data:text/html;charset=utf-8,{}", urlencoding::encode(html))
+}
+
+/// Parses the `req` JSON array passed to an `eval_with_callback` binding (a single
+/// JSON-stringified payload, either the evaluated value or a `__wv_eval_error` marker)
+fn parse_eval_payload<T: serde::de::DeserializeOwned>(req: &str) -> Result<T, WvError> {
+    let payload = serde_json::from_str::<Vec<String>>(req)
+        .ok()
+        .and_then(|mut args| args.pop());
+    match payload {
+        Some(payload) => match serde_json::from_str::<serde_json::Value>(&payload) {
+            Ok(serde_json::Value::Object(ref map)) if map.contains_key("__wv_eval_error") => {
+                Err(WvError::Unknown(
+                    map["__wv_eval_error"].as_str().unwrap_or("eval error").to_string(),
+                ))
+            }
+            Ok(value) => {
+                serde_json::from_value::<T>(value).map_err(|err| WvError::Unknown(err.to_string()))
+            }
+            Err(err) => Err(WvError::Unknown(err.to_string())),
+        },
+        None => Err(WvError::Unknown("invalid eval callback payload".to_string())),
+    }
+}
+
+/// Builds the `(status, body)` pair returned to a `bind_json` JS caller for a handler result
+fn bind_json_response<Ret: serde::Serialize, E: serde::Serialize>(
+    result: Result<Ret, E>,
+) -> (i32, String) {
+    match result {
+        Ok(ret) => match serde_json::to_string(&ret) {
+            Ok(json) => (0, json),
+            Err(err) => (1, err.to_string()),
+        },
+        Err(err) => match serde_json::to_string(&err) {
+            Ok(json) => (1, json),
+            Err(err) => (1, err.to_string()),
+        },
+    }
+}
+
 /// Webview wrapper
 #[derive(Clone)]
 pub struct Webview {
@@ -152,6 +206,74 @@ impl Webview {
             inner: Arc::new(unsafe { webview_create(debug as raw::c_int, ptr::null_mut()) }),
         }
     }
+
+    /// Create a new instance of the webview embedded inside an existing native window,
+    /// given a raw platform handle (`HWND` on Windows, `NSView` on macOS, X11 window on Linux)
+    pub fn create_with_parent(debug: bool, handle: *mut raw::c_void) -> Webview {
+        Webview {
+            inner: Arc::new(unsafe { webview_create(debug as raw::c_int, handle) }),
+        }
+    }
+
+    /// Create a new instance of the webview embedded inside an existing native window,
+    /// using a `raw_window_handle::RawWindowHandle` obtained from winit, fltk, egui, etc
+    pub fn create_from_window_handle(
+        debug: bool,
+        handle: raw_window_handle::RawWindowHandle,
+    ) -> Result<Webview, WvError> {
+        let parent = match handle {
+            #[cfg(target_os = "windows")]
+            raw_window_handle::RawWindowHandle::Win32(handle) => handle.hwnd,
+            #[cfg(target_os = "macos")]
+            raw_window_handle::RawWindowHandle::AppKit(handle) => handle.ns_view,
+            #[cfg(any(target_os = "linux", target_os = "freebsd"))]
+            raw_window_handle::RawWindowHandle::Xlib(handle) => handle.window as *mut raw::c_void,
+            _ => return Err(WvError::Unknown("Unsupported window handle for this platform".to_string())),
+        };
+        Ok(Self::create_with_parent(debug, parent))
+    }
+
+    /// Get the underlying library's version
+    pub fn version() -> String {
+        unsafe {
+            let info = webview_version();
+            CStr::from_ptr((*info).version_number.as_ptr())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Queries the installed WebView2 runtime version, if any
+    #[cfg(target_os = "windows")]
+    pub fn available_backend_version() -> Option<String> {
+        use std::{ffi::c_void, ptr};
+
+        // WebView2Loader(Static) is already linked by build.rs for this target
+        extern "C" {
+            fn GetAvailableCoreWebView2BrowserVersionString(
+                browser_executable_folder: *const u16,
+                version_info: *mut *mut u16,
+            ) -> i32;
+            fn CoTaskMemFree(pv: *mut c_void);
+        }
+
+        unsafe {
+            let mut version_info: *mut u16 = ptr::null_mut();
+            let hr = GetAvailableCoreWebView2BrowserVersionString(ptr::null(), &mut version_info);
+            if hr != 0 || version_info.is_null() {
+                return None;
+            }
+            let mut len = 0usize;
+            while *version_info.add(len) != 0 {
+                len += 1;
+            }
+            let slice = std::slice::from_raw_parts(version_info, len);
+            let result = String::from_utf16_lossy(slice);
+            CoTaskMemFree(version_info as *mut c_void);
+            Some(result)
+        }
+    }
+
     /// Navigate to a url
     pub fn navigate(&self, url: &str) -> Result<(), WvError> {
         let url = CString::new(url)?;
@@ -165,10 +287,18 @@ impl Webview {
         }
     }
 
+    /// Loads a url or inline HTML markup into the webview
+    pub fn load(&self, content: Content) -> Result<(), WvError> {
+        match content {
+            Content::Url(url) => self.navigate(url),
+            // MS Edge chromium based also requires utf-8
+            Content::Html(html) => self.navigate(&html_data_url(html)),
+        }
+    }
+
     /// Set the html content of the weview window
     pub fn set_html(&self, html: &str) -> Result<(), WvError> {
-        // MS Edge chromium based also requires utf-8
-        self.navigate(&(String::from("data:text/html;charset=utf-8,") + html))
+        self.load(Content::Html(html))
     }
 
     /// Injects JavaScript code at the initialization of the new page
@@ -197,6 +327,36 @@ impl Webview {
         }
     }
 
+    /// Evaluates arbitrary JavaScript code and passes the result back to `cb`
+    pub fn eval_with_callback<T, F>(&self, js: &str, cb: F) -> Result<(), WvError>
+    where
+        T: serde::de::DeserializeOwned,
+        F: FnOnce(Result<T, WvError>) + 'static,
+    {
+        static COUNTER: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let id = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let name = format!("__wv_eval_callback_{}", id);
+
+        let wrapper = format!(
+            "(function(){{ try {{ var __wv_result = ({}); window.{name}(JSON.stringify(__wv_result)); }} catch (__wv_err) {{ window.{name}(JSON.stringify({{ __wv_eval_error: String(__wv_err) }})); }} }})();",
+            js,
+            name = name,
+        );
+
+        let webview = self.clone();
+        let unbind_name = name.clone();
+        let cb = std::cell::RefCell::new(Some(cb));
+        self.bind(&name, move |seq, req| {
+            if let Some(cb) = cb.borrow_mut().take() {
+                cb(parse_eval_payload(req));
+            }
+            let _ = webview.return_(seq, 0, "null");
+            let _ = webview.unbind(&unbind_name);
+        })?;
+
+        self.eval(&wrapper)
+    }
+
     /// Posts a function to be executed on the main thread
     pub fn dispatch<F>(&mut self, f: F) -> Result<(), WvError>
     where
@@ -263,6 +423,25 @@ impl Webview {
         }
     }
 
+    /// Binds a Rust closure as a global JavaScript function, (de)serializing the arguments
+    /// and return value via serde so that no manual JSON/CString handling is required
+    pub fn bind_json<Args, Ret, E, F>(&self, name: &str, mut f: F) -> Result<(), WvError>
+    where
+        Args: serde::de::DeserializeOwned,
+        Ret: serde::Serialize,
+        E: serde::Serialize,
+        F: FnMut(&Webview, Args) -> Result<Ret, E> + 'static,
+    {
+        let webview = self.clone();
+        self.bind(name, move |seq, req| {
+            let (status, result) = match serde_json::from_str::<Args>(req) {
+                Ok(args) => bind_json_response(f(&webview, args)),
+                Err(err) => bind_json_response::<(), _>(Err(err.to_string())),
+            };
+            let _ = webview.return_(seq, status, &result);
+        })
+    }
+
     /// Unbinds a native C callback so that it will appear under the given name as a global JavaScript function
     pub fn unbind(&self, name: &str) -> Result<(), WvError> {
         let name = CString::new(name)?;
@@ -335,3 +514,129 @@ impl Webview {
         Self { inner }
     }
 }
+
+/// Builder for a `Webview`
+#[derive(Default)]
+pub struct WebviewBuilder<'a> {
+    debug: bool,
+    title: Option<&'a str>,
+    size: Option<(i32, i32, SizeHint)>,
+    parent: Option<*mut raw::c_void>,
+    content: Option<Content<'a>>,
+    init_scripts: Vec<&'a str>,
+}
+
+unsafe impl<'a> Send for WebviewBuilder<'a> {}
+unsafe impl<'a> Sync for WebviewBuilder<'a> {}
+
+impl<'a> WebviewBuilder<'a> {
+    /// Create a new, empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable the webview's debug mode (developer tools, etc)
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Set the window title
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set the window size and sizing hint
+    pub fn size(mut self, width: i32, height: i32, hint: SizeHint) -> Self {
+        self.size = Some((width, height, hint));
+        self
+    }
+
+    /// Embed the webview inside an existing native window
+    pub fn parent(mut self, handle: *mut raw::c_void) -> Self {
+        self.parent = Some(handle);
+        self
+    }
+
+    /// Navigate to a url
+    pub fn url(mut self, url: &'a str) -> Self {
+        self.content = Some(Content::Url(url));
+        self
+    }
+
+    /// Load inline HTML markup
+    pub fn html(mut self, html: &'a str) -> Self {
+        self.content = Some(Content::Html(html));
+        self
+    }
+
+    /// Inject JavaScript code at the initialization of the new page
+    pub fn init_script(mut self, script: &'a str) -> Self {
+        self.init_scripts.push(script);
+        self
+    }
+
+    /// Build the `Webview`
+    pub fn build(self) -> Result<Webview, WvError> {
+        let mut wv = match self.parent {
+            Some(handle) => Webview::create_with_parent(self.debug, handle),
+            None => Webview::create_no_win(self.debug),
+        };
+        if let Some(title) = self.title {
+            wv.set_title(title)?;
+        }
+        if let Some((width, height, hint)) = self.size {
+            wv.set_size(width, height, hint)?;
+        }
+        for script in self.init_scripts {
+            wv.init(script)?;
+        }
+        if let Some(content) = self.content {
+            wv.load(content)?;
+        }
+        Ok(wv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_data_url_encodes_reserved_characters() {
+        let url = html_data_url("<h1>#1 100% A&B</h1>");
+        assert_eq!(
+            url,
+            "data:text/html;charset=utf-8,%3Ch1%3E%231%20100%25%20A%26B%3C%2Fh1%3E"
+        );
+    }
+
+    #[test]
+    fn bind_json_response_ok_is_status_zero() {
+        let (status, body) = bind_json_response::<_, ()>(Ok(42));
+        assert_eq!(status, 0);
+        assert_eq!(body, "42");
+    }
+
+    #[test]
+    fn bind_json_response_err_is_nonzero_status() {
+        let (status, body) = bind_json_response::<(), _>(Err("bad input".to_string()));
+        assert_eq!(status, 1);
+        assert_eq!(body, "\"bad input\"");
+    }
+
+    #[test]
+    fn parse_eval_payload_decodes_value() {
+        let req = r#"["42"]"#;
+        let result: Result<i32, WvError> = parse_eval_payload(req);
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[test]
+    fn parse_eval_payload_reports_js_error() {
+        let req = r#"["{\"__wv_eval_error\":\"boom\"}"]"#;
+        let result: Result<i32, WvError> = parse_eval_payload(req);
+        assert!(matches!(result, Err(WvError::Unknown(ref msg)) if msg == "boom"));
+    }
+}